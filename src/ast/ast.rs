@@ -0,0 +1,62 @@
+use crate::token::Token;
+
+/// every AST node can report the literal text of the token it starts with,
+/// mainly for debugging and error messages
+pub trait Node {
+    fn token_literal(&self) -> String;
+}
+
+pub trait Statement: Node {
+    fn statement_node(&self);
+}
+
+pub trait Expression: Node {
+    fn expression_node(&self);
+}
+
+/// the root node of every parsed program: an ordered list of statements
+#[derive(Default)]
+pub struct Program {
+    pub statements: Vec<Box<dyn Statement>>,
+}
+
+impl Node for Program {
+    fn token_literal(&self) -> String {
+        match self.statements.first() {
+            Some(stmt) => stmt.token_literal(),
+            None => String::new(),
+        }
+    }
+}
+
+pub struct Identifier {
+    pub token: Token,
+    pub value: String,
+}
+
+impl Node for Identifier {
+    fn token_literal(&self) -> String {
+        self.token.literal.clone()
+    }
+}
+
+impl Expression for Identifier {
+    fn expression_node(&self) {}
+}
+
+/// `let <name> = <value>;`
+pub struct LetStatement {
+    pub token: Token,
+    pub name: Identifier,
+    pub value: Option<Box<dyn Expression>>,
+}
+
+impl Node for LetStatement {
+    fn token_literal(&self) -> String {
+        self.token.literal.clone()
+    }
+}
+
+impl Statement for LetStatement {
+    fn statement_node(&self) {}
+}
@@ -1,11 +1,67 @@
+use unicode_xid::UnicodeXID;
+
 use crate::token;
-use crate::token::Token;
+use crate::token::{Literal, Position, Radix, Span, Token, TokenType};
+
+/// an identifier may start with `_` or any XID_Start codepoint (which
+/// already covers ASCII and non-Latin letters)
+fn is_identifier_start(c: char) -> bool {
+    c == '_' || UnicodeXID::is_xid_start(c)
+}
+
+/// after the first character, an identifier may also contain digits and
+/// any XID_Continue codepoint
+fn is_identifier_continue(c: char) -> bool {
+    c == '_' || UnicodeXID::is_xid_continue(c)
+}
+
+/// A recoverable problem encountered while lexing. The lexer still advances
+/// past the offending text so a caller (e.g. a REPL) can keep calling
+/// `next_token` and collect every error in a line rather than stopping at
+/// the first one.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LexError {
+    UnexpectedChar(char, Span),
+    UnterminatedString(Span),
+    UnterminatedBlockComment(Span),
+    InvalidNumber { literal: String, span: Span },
+}
+
+impl LexError {
+    pub fn span(&self) -> Span {
+        match self {
+            LexError::UnexpectedChar(_, span) => *span,
+            LexError::UnterminatedString(span) => *span,
+            LexError::UnterminatedBlockComment(span) => *span,
+            LexError::InvalidNumber { span, .. } => *span,
+        }
+    }
+}
+
+/// maps an identifier's literal text to its keyword token type, falling
+/// back to `IDENT` for anything that isn't a reserved word
+fn lookup_identifier(ident: &str) -> TokenType {
+    match ident {
+        "fn" => token::FUNCTION,
+        "let" => token::LET,
+        "true" => token::TRUE,
+        "false" => token::FALSE,
+        "if" => token::IF,
+        "else" => token::ELSE,
+        "return" => token::RETURN,
+        _ => token::IDENT,
+    }
+    .to_string()
+}
 
 pub struct Lexer {
     input: String,
     position: usize,
     read_position: usize,
-    ch: String,
+    ch: Option<char>,
+    line: usize,
+    column: usize,
+    done: bool,
 }
 
 impl Lexer {
@@ -14,92 +70,325 @@ impl Lexer {
             input: input.into(),
             position: 0,
             read_position: 0,
-            ch: "\\".into(),
+            ch: None,
+            line: 1,
+            column: 0,
+            done: false,
         };
         lexer.read_char();
         lexer
     }
 
-    pub fn next_token(&mut self) -> Option<Token> {
-        self.skip_whitespaces();
-
-        let tok: Option<Token> = 
-            match self.ch.chars().next() {
-                Some('=')         => Some(Token::new(token::ASSIGN,       &self.ch)),
-                Some(';')         => Some(Token::new(token::SEMICOLON,    &self.ch)),
-                Some('(')         => Some(Token::new(token::LPAREN,       &self.ch)),
-                Some(')')         => Some(Token::new(token::RPAREN,       &self.ch)),
-                Some(',')         => Some(Token::new(token::COMMA,        &self.ch)),
-                Some('+')         => Some(Token::new(token::PLUS,         &self.ch)),
-                Some('{')         => Some(Token::new(token::LBRACE,       &self.ch)),
-                Some('}')         => Some(Token::new(token::RBRACE,       &self.ch)),
-                Some('\\')        => Some(Token::new(token::EOF,          &self.ch)),
-                Some('a'..='z')   => {
-                    let keywords: std::collections::HashMap<&str, &str> = 
-                        [("fn", token::FUNCTION), ("let", token::LET)]
-                            .iter()
-                            .cloned()
-                            .collect();
-                    let lookup_identifier = |id: &str| -> String {
-                        if let Some(ident) = keywords.get(id) {
-                            (&ident).to_string()
-                        } else {
-                            token::IDENT.to_string()
-                        }
-                    };
-                    // type unassigned
-                    let mut tok = Token::new(token::ILLEGAL, self.read_identifier());
-                    tok.token_type = lookup_identifier(&tok.literal);
-                    return Some(tok);
-                },
-                Some('0'..='9')   => return Some(Token::new(token::INT,   self.read_number())),
-                _                 => Some(Token::new(token::ILLEGAL,      &self.ch)),
-            };
-        self.read_char();
-        tok
+    /// the position of the character currently held in `self.ch`
+    fn current_position(&self) -> Position {
+        Position {
+            line: self.line,
+            column: self.column,
+            offset: self.position,
+        }
+    }
+
+    pub fn next_token(&mut self) -> Option<Result<Token, LexError>> {
+        if let Err(err) = self.skip_trivia() {
+            return Some(Err(err));
+        }
+
+        let start = self.current_position();
+
+        match self.ch {
+            // two-char operators: consume both characters before taking
+            // `end`, so the span covers the whole operator
+            Some('=') if self.peek_char() == Some('=') => {
+                self.read_char();
+                self.read_char();
+                let end = self.current_position();
+                Some(Ok(Token::with_span(token::EQ, "==", Span { start, end })))
+            },
+            Some('!') if self.peek_char() == Some('=') => {
+                self.read_char();
+                self.read_char();
+                let end = self.current_position();
+                Some(Ok(Token::with_span(token::NOT_EQ, "!=", Span { start, end })))
+            },
+            // single-char tokens: consume the character first so `end`
+            // covers it, instead of aliasing `end` to `start`
+            Some('=')         => { self.read_char(); let end = self.current_position(); Some(Ok(Token::with_span(token::ASSIGN,    "=", Span { start, end }))) },
+            Some(';')         => { self.read_char(); let end = self.current_position(); Some(Ok(Token::with_span(token::SEMICOLON, ";", Span { start, end }))) },
+            Some('(')         => { self.read_char(); let end = self.current_position(); Some(Ok(Token::with_span(token::LPAREN,    "(", Span { start, end }))) },
+            Some(')')         => { self.read_char(); let end = self.current_position(); Some(Ok(Token::with_span(token::RPAREN,    ")", Span { start, end }))) },
+            Some(',')         => { self.read_char(); let end = self.current_position(); Some(Ok(Token::with_span(token::COMMA,     ",", Span { start, end }))) },
+            Some('+')         => { self.read_char(); let end = self.current_position(); Some(Ok(Token::with_span(token::PLUS,      "+", Span { start, end }))) },
+            Some('-')         => { self.read_char(); let end = self.current_position(); Some(Ok(Token::with_span(token::MINUS,     "-", Span { start, end }))) },
+            Some('*')         => { self.read_char(); let end = self.current_position(); Some(Ok(Token::with_span(token::ASTERISK,  "*", Span { start, end }))) },
+            Some('/')         => { self.read_char(); let end = self.current_position(); Some(Ok(Token::with_span(token::SLASH,     "/", Span { start, end }))) },
+            Some('!')         => { self.read_char(); let end = self.current_position(); Some(Ok(Token::with_span(token::BANG,      "!", Span { start, end }))) },
+            Some('<')         => { self.read_char(); let end = self.current_position(); Some(Ok(Token::with_span(token::LT,        "<", Span { start, end }))) },
+            Some('>')         => { self.read_char(); let end = self.current_position(); Some(Ok(Token::with_span(token::GT,        ">", Span { start, end }))) },
+            Some('{')         => { self.read_char(); let end = self.current_position(); Some(Ok(Token::with_span(token::LBRACE,    "{", Span { start, end }))) },
+            Some('}')         => { self.read_char(); let end = self.current_position(); Some(Ok(Token::with_span(token::RBRACE,    "}", Span { start, end }))) },
+            // nothing left to consume: don't call `read_char` again, so
+            // repeated calls past end-of-input keep reporting the same
+            // position instead of `read_position` advancing forever
+            None              => Some(Ok(Token::with_span(token::EOF, "", Span { start, end: start }))),
+            Some(c) if is_identifier_start(c) => {
+                // type unassigned
+                let literal = self.read_identifier().to_string();
+                let end = self.current_position();
+                let mut tok = Token::with_span(token::ILLEGAL, literal, Span { start, end });
+                tok.token_type = lookup_identifier(&tok.literal);
+                Some(Ok(tok))
+            },
+            Some('0'..='9')   => {
+                Some(match self.read_number() {
+                    Ok((literal, value)) => {
+                        let end = self.current_position();
+                        let token_type = match value {
+                            Literal::Float(_) => token::FLOAT,
+                            Literal::Int(..) => token::INT,
+                        };
+                        Ok(Token::with_span(token_type, literal, Span { start, end }).with_value(value))
+                    },
+                    Err(literal) => {
+                        let end = self.current_position();
+                        Err(LexError::InvalidNumber { literal, span: Span { start, end } })
+                    },
+                })
+            },
+            Some('"')         => {
+                Some(match self.read_string() {
+                    Ok(value) => Ok(Token::with_span(token::STRING, value, Span { start, end: self.current_position() })),
+                    Err(()) => Err(LexError::UnterminatedString(Span { start, end: self.current_position() })),
+                })
+            },
+            Some(c)           => {
+                self.read_char();
+                Some(Err(LexError::UnexpectedChar(c, Span { start, end: start })))
+            },
+        }
+    }
+
+    /// returns the character at `read_position` without advancing the lexer
+    fn peek_char(&self) -> Option<char> {
+        self.input.get(self.read_position..).and_then(|s| s.chars().next())
     }
 
     /// finds all subsequent characters that are letters and returns a string
     /// slice representing the identifier's value
     fn read_identifier(&mut self) -> &str {
         let position = self.position;
-        while let Some('a'..='z') = self.ch.chars().next() {
+        while self.ch.is_some_and(is_identifier_continue) {
             self.read_char()
         }
         &self.input[position..self.position]
     }
 
-    /// finds all subsequent characters that are numbers and returns a string
-    /// slice representing the number value
-    fn read_number(&mut self) -> &str {
+    /// reads a decimal integer, a `0x`/`0o`/`0b` radix integer, or a float, and
+    /// parses it into a `Literal` so later stages never re-parse the text.
+    /// Returns the literal's source text alongside the parsed value on
+    /// success, or the (possibly partial) literal text on failure.
+    fn read_number(&mut self) -> Result<(String, Literal), String> {
         let position = self.position;
-        while let Some('0'..='9') = self.ch.chars().next() {
-            self.read_char()
+
+        if self.ch == Some('0') {
+            match self.peek_char() {
+                Some('x') | Some('X') => return self.read_radix_int(position, 16, Radix::Hex),
+                Some('o') | Some('O') => return self.read_radix_int(position, 8, Radix::Octal),
+                Some('b') | Some('B') => return self.read_radix_int(position, 2, Radix::Binary),
+                _ => {},
+            }
         }
-        &self.input[position..self.position]
+
+        while let Some('0'..='9') = self.ch {
+            self.read_char();
+        }
+
+        let mut is_float = false;
+        if self.ch == Some('.') {
+            if let Some('0'..='9') = self.peek_char() {
+                is_float = true;
+                self.read_char();
+                while let Some('0'..='9') = self.ch {
+                    self.read_char();
+                }
+            }
+        }
+
+        // a second `.` (e.g. `1.2.3`) is malformed; consume it so the caller's
+        // span covers the whole bad literal instead of stopping mid-way
+        if self.ch == Some('.') {
+            while let Some('0'..='9' | '.') = self.ch {
+                self.read_char();
+            }
+            return Err(self.input[position..self.position].to_string());
+        }
+
+        let literal = self.input[position..self.position].to_string();
+        if is_float {
+            literal.parse::<f64>().map(|f| (literal.clone(), Literal::Float(f))).map_err(|_| literal)
+        } else {
+            literal.parse::<i64>().map(|i| (literal.clone(), Literal::Int(i, Radix::Decimal))).map_err(|_| literal)
+        }
+    }
+
+    /// reads the digits of a `0x`/`0o`/`0b` prefixed integer. `self.ch` must be
+    /// the leading `0` on entry. `start` is the byte offset of that `0`.
+    fn read_radix_int(&mut self, start: usize, radix: u32, kind: Radix) -> Result<(String, Literal), String> {
+        self.read_char(); // consume '0'
+        self.read_char(); // consume the 'x'/'o'/'b' prefix letter
+
+        let digits_start = self.position;
+        while self.ch.is_some_and(|c| c.is_digit(radix)) {
+            self.read_char();
+        }
+
+        let literal = self.input[start..self.position].to_string();
+        if self.position == digits_start {
+            return Err(literal);
+        }
+
+        i64::from_str_radix(&self.input[digits_start..self.position], radix)
+            .map(|v| (literal.clone(), Literal::Int(v, kind)))
+            .map_err(|_| literal)
     }
 
-    /// reads the next character
+    /// reads the body of a string literal, translating `\n`, `\t`, `\"` and `\\`
+    /// escapes into their real bytes. `self.ch` must be the opening `"` on entry;
+    /// on success it is left one past the closing `"`. Returns `Err(())` if EOF
+    /// is reached before the string is closed.
+    fn read_string(&mut self) -> Result<String, ()> {
+        let mut value = String::new();
+        loop {
+            self.read_char();
+            match self.ch {
+                Some('"') => {
+                    self.read_char();
+                    return Ok(value);
+                },
+                Some('\\') => {
+                    self.read_char();
+                    match self.ch {
+                        Some('n') => value.push('\n'),
+                        Some('t') => value.push('\t'),
+                        Some('"') => value.push('"'),
+                        Some('\\') => value.push('\\'),
+                        Some(other) => value.push(other),
+                        None => return Err(()),
+                    }
+                },
+                Some(c) => value.push(c),
+                None => return Err(()),
+            }
+        }
+    }
+
+    /// reads the next character, advancing `line`/`column` to track where it came from
     fn read_char(&mut self) {
-        if self.read_position >= self.input.len() {
-            self.ch = "\\".into();
-        } else if let Some(next_input) = self.input.get(self.read_position..self.read_position + 1) {   
-            self.ch = next_input.into();
+        if self.ch == Some('\n') {
+            self.line += 1;
+            self.column = 0;
         }
+        self.column += 1;
+
+        self.ch = self.input.get(self.read_position..).and_then(|s| s.chars().next());
         self.position = self.read_position;
-        self.read_position += 1;
+        self.read_position += self.ch.map_or(1, |c| c.len_utf8());
+    }
+
+    /// skips whitespace, `//` line comments, and nested `/* */` block
+    /// comments before the next token, looping since any of them may
+    /// follow another
+    fn skip_trivia(&mut self) -> Result<(), LexError> {
+        loop {
+            self.skip_whitespace_chars();
+            if self.ch == Some('/') && self.peek_char() == Some('/') {
+                self.skip_line_comment();
+            } else if self.ch == Some('/') && self.peek_char() == Some('*') {
+                self.skip_block_comment()?;
+            } else {
+                break;
+            }
+        }
+        Ok(())
     }
 
-    fn skip_whitespaces(&mut self) {
-        while let Some(c) = self.ch.chars().next() {
+    fn skip_whitespace_chars(&mut self) {
+        while let Some(c) = self.ch {
             if c.is_whitespace() {
                 self.read_char();
-                println!("Char is {}", &self.ch);
             } else {
                 break;
             }
         }
     }
+
+    fn skip_line_comment(&mut self) {
+        while let Some(c) = self.ch {
+            if c == '\n' {
+                break;
+            }
+            self.read_char();
+        }
+    }
+
+    /// skips a `/* */` block comment, tracking nesting so
+    /// `/* a /* b */ c */` is skipped as a single comment. `self.ch` must be
+    /// the opening `/` on entry.
+    fn skip_block_comment(&mut self) -> Result<(), LexError> {
+        let start = self.current_position();
+        self.read_char(); // consume '/'
+        self.read_char(); // consume '*'
+
+        let mut depth = 1;
+        loop {
+            match self.ch {
+                None => {
+                    let end = self.current_position();
+                    return Err(LexError::UnterminatedBlockComment(Span { start, end }));
+                },
+                Some('*') if self.peek_char() == Some('/') => {
+                    self.read_char();
+                    self.read_char();
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                },
+                Some('/') if self.peek_char() == Some('*') => {
+                    self.read_char();
+                    self.read_char();
+                    depth += 1;
+                },
+                Some(_) => {
+                    self.read_char();
+                },
+            }
+        }
+    }
+}
+
+impl Iterator for Lexer {
+    type Item = Result<Token, LexError>;
+
+    /// yields each token in turn and `None` once the EOF token has been
+    /// produced, so `for tok in lexer` terminates naturally
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let result = self.next_token()?;
+        if matches!(&result, Ok(tok) if tok.token_type == token::EOF) {
+            self.done = true;
+        }
+        Some(result)
+    }
+}
+
+/// lexes `input` in full and collects the resulting tokens, including a
+/// terminating `EOF` token. Lex errors are dropped; callers that need to
+/// see them should iterate the `Lexer` directly instead.
+pub fn lex(input: &str) -> Vec<Token> {
+    Lexer::new(input).filter_map(Result::ok).collect()
 }
 
 
@@ -116,13 +405,13 @@ fn test_next_token() {
         (token::RBRACE,     "}"),
         (token::COMMA,      ","),
         (token::SEMICOLON,  ";"),
-        (token::EOF,        "\\"),
+        (token::EOF,        ""),
     ];
 
     let mut l = Lexer::new(input);
 
     for (i, (expected_token, expected_literal)) in tests.iter().enumerate() {
-        if let Some(tok) = l.next_token() {
+        if let Some(Ok(tok)) = l.next_token() {
             assert_eq!(
                 &tok.token_type, expected_token,
                 "tests[{}] - tokentype wrong. expected={}, got={}",
@@ -136,16 +425,24 @@ fn test_next_token() {
         }
     }
 }
+#[test]
+fn test_next_token_span_covers_the_character() {
+    let mut l = Lexer::new("+");
+    let tok = l.next_token().unwrap().unwrap();
+    assert_eq!(tok.span.start.offset, 0);
+    assert_eq!(tok.span.end.offset, 1);
+}
+
 #[test]
 fn test_next_token_2() {
     let input = "
         let five=5;
         let ten=10;
-        
+
         let add = fn(x, y) {
             x + y;
         };
-        
+
         let result = add(five, ten);";
     let tests: [(&str, &str); 37] =
     [
@@ -185,13 +482,13 @@ fn test_next_token_2() {
         (token::IDENT,      "ten"),
         (token::RPAREN,     ")"),
         (token::SEMICOLON,  ";"),
-        (token::EOF,        "\\"),
+        (token::EOF,        ""),
     ];
 
     let mut l = Lexer::new(input);
 
     for (i, (expected_token, expected_literal)) in tests.iter().enumerate() {
-        if let Some(tok) = l.next_token() {
+        if let Some(Ok(tok)) = l.next_token() {
             assert_eq!(
                 &tok.token_type, expected_token,
                 "tests[{}] - tokentype wrong. expected={}, got={} with value {}",
@@ -204,4 +501,262 @@ fn test_next_token_2() {
             );
         }
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_next_token_two_char_operator_span_covers_both_characters() {
+    let mut l = Lexer::new("==");
+    let tok = l.next_token().unwrap().unwrap();
+    assert_eq!(tok.span.start.offset, 0);
+    assert_eq!(tok.span.end.offset, 2);
+}
+
+#[test]
+fn test_next_token_two_char_operators() {
+    let input = "10 == 10; 10 != 9;";
+    let tests: [(&str, &str); 8] = [
+        (token::INT,        "10"),
+        (token::EQ,         "=="),
+        (token::INT,        "10"),
+        (token::SEMICOLON,  ";"),
+        (token::INT,        "10"),
+        (token::NOT_EQ,     "!="),
+        (token::INT,        "9"),
+        (token::SEMICOLON,  ";"),
+    ];
+
+    let mut l = Lexer::new(input);
+
+    for (i, (expected_token, expected_literal)) in tests.iter().enumerate() {
+        if let Some(Ok(tok)) = l.next_token() {
+            assert_eq!(
+                &tok.token_type, expected_token,
+                "tests[{}] - tokentype wrong. expected={}, got={}",
+                i, expected_token, tok.token_type,
+            );
+            assert_eq!(
+                &tok.literal, expected_literal,
+                "tests[{}] - literal wrong. expected={}, got={}",
+                i, expected_literal, tok.literal,
+            );
+        }
+    }
+}
+
+#[test]
+fn test_next_token_operators_and_keywords() {
+    let input = "
+        if (5 < 10) {
+            return true;
+        } else {
+            return false;
+        }
+        !- / *5;
+        5 > 5;";
+    let tests: [(&str, &str); 23] = [
+        (token::IF,         "if"),
+        (token::LPAREN,     "("),
+        (token::INT,        "5"),
+        (token::LT,         "<"),
+        (token::INT,        "10"),
+        (token::RPAREN,     ")"),
+        (token::LBRACE,     "{"),
+        (token::RETURN,     "return"),
+        (token::TRUE,       "true"),
+        (token::SEMICOLON,  ";"),
+        (token::RBRACE,     "}"),
+        (token::ELSE,       "else"),
+        (token::LBRACE,     "{"),
+        (token::RETURN,     "return"),
+        (token::FALSE,      "false"),
+        (token::SEMICOLON,  ";"),
+        (token::RBRACE,     "}"),
+        (token::BANG,       "!"),
+        (token::MINUS,      "-"),
+        (token::SLASH,      "/"),
+        (token::ASTERISK,   "*"),
+        (token::INT,        "5"),
+        (token::SEMICOLON,  ";"),
+    ];
+
+    let mut l = Lexer::new(input);
+
+    for (i, (expected_token, expected_literal)) in tests.iter().enumerate() {
+        if let Some(Ok(tok)) = l.next_token() {
+            assert_eq!(
+                &tok.token_type, expected_token,
+                "tests[{}] - tokentype wrong. expected={}, got={}",
+                i, expected_token, tok.token_type,
+            );
+            assert_eq!(
+                &tok.literal, expected_literal,
+                "tests[{}] - literal wrong. expected={}, got={}",
+                i, expected_literal, tok.literal,
+            );
+        }
+    }
+}
+
+#[test]
+fn test_next_token_strings() {
+    let input = "\"foobar\"
+        \"foo bar\"
+        \"escaped \\n \\t \\\" \\\\ end\"";
+    let tests: [(&str, &str); 3] = [
+        (token::STRING, "foobar"),
+        (token::STRING, "foo bar"),
+        (token::STRING, "escaped \n \t \" \\ end"),
+    ];
+
+    let mut l = Lexer::new(input);
+
+    for (i, (expected_token, expected_literal)) in tests.iter().enumerate() {
+        if let Some(Ok(tok)) = l.next_token() {
+            assert_eq!(
+                &tok.token_type, expected_token,
+                "tests[{}] - tokentype wrong. expected={}, got={}",
+                i, expected_token, tok.token_type,
+            );
+            assert_eq!(
+                &tok.literal, expected_literal,
+                "tests[{}] - literal wrong. expected={}, got={}",
+                i, expected_literal, tok.literal,
+            );
+        }
+    }
+}
+
+#[test]
+fn test_next_token_unterminated_string() {
+    let input = "\"never closed";
+    let mut l = Lexer::new(input);
+
+    let result = l.next_token().expect("lexer should still produce a result");
+    assert!(matches!(result, Err(LexError::UnterminatedString(_))));
+}
+
+#[test]
+fn test_next_token_numbers() {
+    let input = "5 2.5 0x1F 0o17 0b101";
+    let mut l = Lexer::new(input);
+
+    let tok = l.next_token().unwrap().unwrap();
+    assert_eq!(tok.token_type, token::INT);
+    assert_eq!(tok.literal, "5");
+    assert_eq!(tok.value, Some(token::Literal::Int(5, token::Radix::Decimal)));
+
+    let tok = l.next_token().unwrap().unwrap();
+    assert_eq!(tok.token_type, token::FLOAT);
+    assert_eq!(tok.literal, "2.5");
+    assert_eq!(tok.value, Some(token::Literal::Float(2.5)));
+
+    let tok = l.next_token().unwrap().unwrap();
+    assert_eq!(tok.token_type, token::INT);
+    assert_eq!(tok.literal, "0x1F");
+    assert_eq!(tok.value, Some(token::Literal::Int(31, token::Radix::Hex)));
+
+    let tok = l.next_token().unwrap().unwrap();
+    assert_eq!(tok.token_type, token::INT);
+    assert_eq!(tok.literal, "0o17");
+    assert_eq!(tok.value, Some(token::Literal::Int(15, token::Radix::Octal)));
+
+    let tok = l.next_token().unwrap().unwrap();
+    assert_eq!(tok.token_type, token::INT);
+    assert_eq!(tok.literal, "0b101");
+    assert_eq!(tok.value, Some(token::Literal::Int(5, token::Radix::Binary)));
+}
+
+#[test]
+fn test_next_token_malformed_numbers() {
+    for input in ["0x", "1.2.3"] {
+        let mut l = Lexer::new(input);
+        let result = l.next_token().unwrap();
+        assert!(matches!(result, Err(LexError::InvalidNumber { .. })), "input {:?} should be rejected", input);
+    }
+}
+
+#[test]
+fn test_next_token_unexpected_char() {
+    let input = "@";
+    let mut l = Lexer::new(input);
+
+    let result = l.next_token().unwrap();
+    assert!(matches!(result, Err(LexError::UnexpectedChar('@', _))));
+}
+
+#[test]
+fn test_lex_collects_tokens_including_eof() {
+    let tokens = lex("let x = 5;");
+    let token_types: Vec<&str> = tokens.iter().map(|t| t.token_type.as_str()).collect();
+    assert_eq!(
+        token_types,
+        vec![token::LET, token::IDENT, token::ASSIGN, token::INT, token::SEMICOLON, token::EOF],
+    );
+}
+
+#[test]
+fn test_lexer_iterator_stops_after_eof() {
+    let mut lexer = Lexer::new("+");
+    assert!(matches!(lexer.next(), Some(Ok(_))));
+    assert!(matches!(lexer.next(), Some(Ok(ref tok)) if tok.token_type == token::EOF));
+    assert!(lexer.next().is_none());
+}
+
+#[test]
+fn test_next_token_eof_span_is_stable_across_repeated_calls() {
+    let mut l = Lexer::new("x");
+    l.next_token();
+
+    let first = l.next_token().unwrap().unwrap();
+    let second = l.next_token().unwrap().unwrap();
+    assert_eq!(first.span, second.span);
+    assert_eq!(second.span.end.offset, 1);
+}
+
+#[test]
+fn test_next_token_unicode_identifiers() {
+    let input = "let myVar = 1; let foo_bar = 2; let x2 = 3; let café = 4;";
+    let mut l = Lexer::new(input);
+
+    for expected in ["let", "myVar", "=", "1", ";", "let", "foo_bar", "=", "2", ";", "let", "x2", "=", "3", ";", "let", "café", "=", "4", ";"] {
+        let tok = l.next_token().unwrap().unwrap();
+        assert_eq!(tok.literal, expected);
+    }
+}
+
+#[test]
+fn test_next_token_skips_comments() {
+    let input = "
+        // a line comment
+        let x = 5; // trailing comment
+        /* a block
+           comment */
+        let y = /* inline */ 10;
+        /* nested /* comment */ still going */
+        let z = 15;";
+
+    let toks = lex(input);
+    let tokens: Vec<(&str, &str)> = toks
+        .iter()
+        .map(|t| (t.token_type.as_str(), t.literal.as_str()))
+        .filter(|(tt, _)| *tt != token::EOF)
+        .collect();
+
+    assert_eq!(
+        tokens,
+        vec![
+            (token::LET, "let"), (token::IDENT, "x"), (token::ASSIGN, "="), (token::INT, "5"), (token::SEMICOLON, ";"),
+            (token::LET, "let"), (token::IDENT, "y"), (token::ASSIGN, "="), (token::INT, "10"), (token::SEMICOLON, ";"),
+            (token::LET, "let"), (token::IDENT, "z"), (token::ASSIGN, "="), (token::INT, "15"), (token::SEMICOLON, ";"),
+        ],
+    );
+}
+
+#[test]
+fn test_next_token_unterminated_block_comment() {
+    let input = "/* never closed";
+    let mut l = Lexer::new(input);
+
+    let result = l.next_token().unwrap();
+    assert!(matches!(result, Err(LexError::UnterminatedBlockComment(_))));
+}
@@ -0,0 +1,8 @@
+#[path = "token/token.rs"]
+pub mod token;
+#[path = "lexer/lexer.rs"]
+pub mod lexer;
+#[path = "ast/ast.rs"]
+pub mod ast;
+#[path = "parser/parser.rs"]
+pub mod parser;
@@ -0,0 +1,175 @@
+use crate::ast::{Identifier, LetStatement, Program, Statement};
+use crate::lexer::{LexError, Lexer};
+use crate::token;
+use crate::token::{Span, Token, TokenType};
+
+/// something that went wrong while turning tokens into an AST: either the
+/// lexer handed back a `LexError`, or a token didn't match what the grammar
+/// expected at that point
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    Lex(LexError),
+    UnexpectedToken {
+        expected: TokenType,
+        got: TokenType,
+        span: Span,
+    },
+}
+
+pub struct Parser {
+    lexer: Lexer,
+    cur_token: Token,
+    peek_token: Token,
+    pub errors: Vec<ParseError>,
+}
+
+impl Parser {
+    pub fn new(lexer: Lexer) -> Self {
+        let mut parser = Parser {
+            lexer,
+            cur_token: Token::new(token::ILLEGAL, ""),
+            peek_token: Token::new(token::ILLEGAL, ""),
+            errors: Vec::new(),
+        };
+        // prime cur_token/peek_token so both are populated before parsing starts
+        parser.advance_token();
+        parser.advance_token();
+        parser
+    }
+
+    /// pulls the next real token out of the lexer, recording (and skipping
+    /// past) any lex errors along the way rather than stopping on them
+    fn read_token(&mut self) -> Token {
+        loop {
+            match self.lexer.next_token() {
+                Some(Ok(tok)) => return tok,
+                Some(Err(err)) => self.errors.push(ParseError::Lex(err)),
+                None => return Token::new(token::EOF, ""),
+            }
+        }
+    }
+
+    fn advance_token(&mut self) {
+        let next = self.read_token();
+        self.cur_token = std::mem::replace(&mut self.peek_token, next);
+    }
+
+    pub fn parse_program(&mut self) -> Program {
+        let mut program = Program::default();
+
+        while self.cur_token.token_type != token::EOF {
+            if let Some(stmt) = self.parse_statement() {
+                program.statements.push(stmt);
+            }
+            self.advance_token();
+        }
+
+        program
+    }
+
+    fn parse_statement(&mut self) -> Option<Box<dyn Statement>> {
+        match self.cur_token.token_type.as_str() {
+            token::LET => self.parse_let_statement(),
+            _ => None,
+        }
+    }
+
+    fn parse_let_statement(&mut self) -> Option<Box<dyn Statement>> {
+        let let_token = self.cur_token.clone();
+
+        if !self.expect_peek(token::IDENT) {
+            return None;
+        }
+        let name = Identifier {
+            token: self.cur_token.clone(),
+            value: self.cur_token.literal.clone(),
+        };
+
+        if !self.expect_peek(token::ASSIGN) {
+            return None;
+        }
+
+        // TODO: we're skipping the expression until expression parsing exists
+        while self.peek_token.token_type != token::SEMICOLON && self.peek_token.token_type != token::EOF {
+            self.advance_token();
+        }
+
+        if !self.expect_peek(token::SEMICOLON) {
+            return None;
+        }
+
+        Some(Box::new(LetStatement {
+            token: let_token,
+            name,
+            value: None,
+        }))
+    }
+
+    /// advances past `peek_token` if it has the expected type, recording a
+    /// parse error and leaving the parser in place otherwise
+    fn expect_peek<S: Into<TokenType>>(&mut self, expected: S) -> bool {
+        let expected = expected.into();
+        if self.peek_token.token_type == expected {
+            self.advance_token();
+            true
+        } else {
+            self.errors.push(ParseError::UnexpectedToken {
+                expected,
+                got: self.peek_token.token_type.clone(),
+                span: self.peek_token.span,
+            });
+            false
+        }
+    }
+}
+
+#[test]
+fn test_let_statements() {
+    let input = "
+        let x = 5;
+        let y = 10;
+        let foobar = 838383;";
+
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert!(parser.errors.is_empty(), "parser had errors: {:?}", parser.errors);
+    assert_eq!(program.statements.len(), 3);
+
+    for stmt in &program.statements {
+        assert_eq!(stmt.token_literal(), "let");
+    }
+}
+
+#[test]
+fn test_let_statement_missing_semicolon() {
+    let input = "let x = 5";
+
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert!(program.statements.is_empty());
+    assert!(!parser.errors.is_empty());
+    assert!(matches!(
+        parser.errors[0],
+        ParseError::UnexpectedToken { .. }
+    ));
+}
+
+#[test]
+fn test_let_statement_errors() {
+    let input = "let = 5;";
+
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program();
+
+    assert!(program.statements.is_empty());
+    assert!(!parser.errors.is_empty());
+    assert!(matches!(
+        parser.errors[0],
+        ParseError::UnexpectedToken { .. }
+    ));
+}
@@ -1,10 +1,31 @@
 pub type TokenType = String;
 
+/// A single point in the source text, as both a human-facing line/column
+/// pair and a byte offset for slicing back into the original input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+    pub offset: usize,
+}
+
+/// The range of source text a token was lexed from, from the first
+/// character up to (but not including) the one after the last.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    pub start: Position,
+    pub end: Position,
+}
+
 pub const ILLEGAL: &str = "ILLEGAL";
 pub const EOF: &str = "EOF";
 pub const IDENT: &str = "IDENT";
 pub const INT: &str = "INT";
+pub const FLOAT: &str = "FLOAT";
+pub const STRING: &str = "STRING";
 pub const ASSIGN: &str = "=";
+pub const EQ: &str = "==";
+pub const NOT_EQ: &str = "!=";
 pub const PLUS: &str = "+";
 pub const COMMA: &str = ",";
 pub const SEMICOLON: &str = ";";
@@ -14,18 +35,66 @@ pub const LBRACE: &str = "{";
 pub const RBRACE: &str = "}";
 pub const FUNCTION: &str = "FUNCTION";
 pub const LET: &str = "LET";
+pub const MINUS: &str = "-";
+pub const ASTERISK: &str = "*";
+pub const SLASH: &str = "/";
+pub const BANG: &str = "!";
+pub const LT: &str = "<";
+pub const GT: &str = ">";
+pub const TRUE: &str = "TRUE";
+pub const FALSE: &str = "FALSE";
+pub const IF: &str = "IF";
+pub const ELSE: &str = "ELSE";
+pub const RETURN: &str = "RETURN";
+
+/// The radix an integer literal was written in, preserved so later stages
+/// can tell `0x10` and `16` apart even though both parse to the same value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Radix {
+    Decimal,
+    Hex,
+    Octal,
+    Binary,
+}
+
+/// A numeric literal's parsed value, computed once during lexing so the
+/// parser never needs to re-run `str::parse` on a token's raw text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Literal {
+    Int(i64, Radix),
+    Float(f64),
+}
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Token {
     pub token_type: TokenType,
     pub literal: String,
+    pub span: Span,
+    pub value: Option<Literal>,
 }
 
 impl Token {
-    pub fn new<'a, S: Into<String>>(token_type: S, literal: S) -> Token {
+    pub fn new<S: Into<String>>(token_type: S, literal: S) -> Token {
         Token {
             token_type: token_type.into(),
             literal: literal.into(),
+            span: Span::default(),
+            value: None,
         }
     }
+
+    pub fn with_span<T: Into<String>, L: Into<String>>(token_type: T, literal: L, span: Span) -> Token {
+        Token {
+            token_type: token_type.into(),
+            literal: literal.into(),
+            span,
+            value: None,
+        }
+    }
+
+    /// attaches a parsed numeric value to the token
+    pub fn with_value(mut self, value: Literal) -> Token {
+        self.value = Some(value);
+        self
+    }
 }
\ No newline at end of file